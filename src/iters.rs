@@ -5,6 +5,9 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+use std::cmp;
+use std::marker::PhantomData;
+
 use vecs::{Packable, Packed};
 
 /// An iterator which automatically packs the values it iterates over into SIMD
@@ -25,14 +28,39 @@ pub trait PackedIterator : Sized + ExactSizeIterator {
     /// elements.
     fn scalar_position(&self) -> usize;
 
+    /// Return the number of elements not yet consumed from either end of
+    /// this iterator. Unlike `scalar_len() - scalar_position()`, this
+    /// accounts for elements already taken off the back by
+    /// `next_vector_back`/`next_partial_back`, so it stays accurate during
+    /// back-only or mixed front/back consumption.
+    fn scalar_remaining(&self) -> usize;
+
     /// Pack and return a vector containing the next `self.width()` elements
     /// of the iterator, or return None if there aren't enough elements left
     fn next_vector(&mut self) -> Option<Self::Vector>;
 
     /// Pack and return a partially full vector containing upto the next
     /// `self.width()` of the iterator, or None if no elements are left.
-    /// Elements which are not filled are instead initialized to default.
-    fn next_partial(&mut self, default: Self::Vector) -> Option<Self::Vector>;
+    /// Lanes which are not filled are instead initialized to the
+    /// corresponding lane of `default`. Returns the number of lanes which
+    /// were actually filled with elements of the iterator, so that callers
+    /// can tell real data from `default` padding.
+    fn next_partial(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)>;
+
+    /// Pack and return a vector containing the previous `self.width()`
+    /// elements of the iterator, or return None if there aren't enough
+    /// elements left. Mirrors `next_vector`, but consumes from the back of
+    /// the iterator, like `core::iter::DoubleEndedIterator::next_back`.
+    fn next_vector_back(&mut self) -> Option<Self::Vector>;
+
+    /// Pack and return a partially full vector containing upto the
+    /// previous `self.width()` of the iterator, or None if no elements are
+    /// left. Lanes which are not filled are instead initialized to the
+    /// corresponding lane of `default`, and the number of lanes actually
+    /// filled is returned alongside the vector. Mirrors `next_partial`, but
+    /// consumes from the back of the iterator; if the front and back
+    /// cursors meet mid-vector, this returns the remaining middle elements.
+    fn next_partial_back(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)>;
 
     #[inline(always)]
     /// Return an iterator which calls `func` on vectors of elements.
@@ -44,6 +72,41 @@ pub trait PackedIterator : Sized + ExactSizeIterator {
         }
     }
 
+    #[inline(always)]
+    /// Return an iterator which pairs each vector of this iterator with the
+    /// corresponding vector of `other`, like `core::iter::Iterator::zip`.
+    /// The resulting iterator's `scalar_len` is the smaller of the two
+    /// sources' lengths.
+    fn zip<U>(self, other: U) -> PackedZip<Self, U>
+        where U : PackedIterator {
+        PackedZip {
+            a: self,
+            b: other
+        }
+    }
+
+    #[inline(always)]
+    /// Shorthand for `self.zip(other).simd_map(func)`.
+    fn simd_zip_map<U, A, B, F>(self, other: U, func: F) -> PackedZipMap<Self, U, F>
+        where U : PackedIterator, F : FnMut((Self::Vector, U::Vector)) -> A, A : Packed<Scalar = B>, B : Packable {
+        PackedIterator::zip(self, other).simd_map(func)
+    }
+
+    #[inline(always)]
+    /// Return an iterator which pairs each vector of this iterator with an
+    /// index vector holding the absolute scalar position of each lane,
+    /// like `core::iter::Iterator::enumerate`. `V` is the unsigned index
+    /// vector type to produce, and must have the same number of lanes as
+    /// `Self::Vector` (e.g. `u32s` for `f32s`).
+    fn simd_enumerate<V>(self) -> PackedEnumerate<Self, V>
+        where V : Packed<Scalar = u32> {
+        PackedEnumerate {
+            iter: self,
+            back_consumed: 0,
+            _marker: PhantomData
+        }
+    }
+
     #[inline(always)]
     /// Return a vector generated by reducing `func` over accumulator `start`
     /// and the values of this iterator, initializing all vectors to `default`
@@ -103,14 +166,14 @@ pub trait PackedIterator : Sized + ExactSizeIterator {
             while let Some(v) = self.next_vector() {
                 acc = func(acc, v);
             }
-            if let Some(v) = self.next_partial(default) {
+            if let Some((v, _)) = self.next_partial(default) {
                 acc = func(acc, v);
             }
             debug_assert!(self.next_partial(default).is_none());
             acc
-        } else if let Some(v) = self.next_partial(default) {
+        } else if let Some((v, _)) = self.next_partial(default) {
             acc = func(start, v);
-            while let Some(v) = self.next_partial(default) {
+            while let Some((v, _)) = self.next_partial(default) {
                 acc = func(acc, v);
             }
             debug_assert!(self.next_partial(default).is_none());
@@ -119,11 +182,119 @@ pub trait PackedIterator : Sized + ExactSizeIterator {
             start
         }
     }
+
+    #[inline(always)]
+    /// Return a vector generated by reducing `func` over the values of this
+    /// iterator in a balanced binary tree, rather than the strict left fold
+    /// used by `simd_reduce`. All vectors are initialized to `default`
+    /// before being populated with elements of the iterator.
+    ///
+    /// This is implemented with a stack of `(level, Vector)` pairs acting as
+    /// a binary counter: each vector pulled off the iterator starts a carry
+    /// at level 0, and is repeatedly folded with any carry already sitting
+    /// at the same level (bumping the level each time) until it finds an
+    /// empty slot. Once the iterator is exhausted, the remaining carries are
+    /// folded together from the smallest level to the largest.
+    ///
+    /// Because most of the folds this performs are between two independent
+    /// carries rather than an accumulator and the next element, the
+    /// dependency chain has depth `log n` instead of `n`, which lets the CPU
+    /// execute unrelated calls to `func` concurrently. As a side effect,
+    /// summing with this instead of `simd_reduce` roughly halves worst-case
+    /// floating-point rounding error.
+    ///
+    /// # Footgun Warning
+    ///
+    /// As with `simd_reduce`, interpreting the resulting vector across
+    /// lanes in a way that is consistent between architectures is still
+    /// your responsibility. See [`Packed::sum`] and [`Packed::product`].
+    ///
+    /// [`Packed::sum`]: vecs/trait.Packed.html#tymethod.sum
+    /// [`Packed::product`]: vecs/trait.Packed.html#tymethod.product
+    fn simd_reduce_tree<F>(&mut self, default: Self::Vector, mut func: F) -> Self::Vector
+        where F : FnMut(Self::Vector, Self::Vector) -> Self::Vector {
+        let mut stack: Vec<(usize, Self::Vector)> = Vec::new();
+
+        {
+            let mut carry_in = |stack: &mut Vec<(usize, Self::Vector)>, v: Self::Vector| {
+                let mut carry = v;
+                let mut level = 0;
+                while let Some(&(top_level, _)) = stack.last() {
+                    if top_level == level {
+                        let (_, top) = stack.pop().unwrap();
+                        carry = func(top, carry);
+                        level += 1;
+                    } else {
+                        break;
+                    }
+                }
+                stack.push((level, carry));
+            };
+
+            while let Some(v) = self.next_vector() {
+                carry_in(&mut stack, v);
+            }
+            if let Some((v, _)) = self.next_partial(default) {
+                carry_in(&mut stack, v);
+            }
+        }
+        debug_assert!(self.next_partial(default).is_none());
+
+        stack.sort_by_key(|&(level, _)| level);
+        let mut entries = stack.into_iter();
+        match entries.next() {
+            Some((_, first)) => entries.fold(first, |acc, (_, v)| func(acc, v)),
+            None => default
+        }
+    }
+
+    #[inline(always)]
+    /// Return a vector generated by reducing `func` over accumulator
+    /// `start` and the values of this iterator, walking from the back of
+    /// the iterator to the front, initializing all vectors to `default`
+    /// before populating them with elements of the iterator. Mirrors
+    /// `simd_reduce`, but built on `next_vector_back` and
+    /// `next_partial_back`.
+    ///
+    /// # Footgun Warning
+    ///
+    /// As with `simd_reduce`, the results of `simd_rreduce` are not
+    /// portable, and it is your responsibility to interpret the result in
+    /// a way that is consistent across different architectures. See
+    /// [`Packed::sum`] and [`Packed::product`].
+    ///
+    /// [`Packed::sum`]: vecs/trait.Packed.html#tymethod.sum
+    /// [`Packed::product`]: vecs/trait.Packed.html#tymethod.product
+    fn simd_rreduce<A, F>(&mut self, start: A, default: Self::Vector, mut func: F) -> A
+        where F : FnMut(A, Self::Vector) -> A {
+        let mut acc: A;
+        if let Some(v) = self.next_vector_back() {
+            acc = func(start, v);
+            while let Some(v) = self.next_vector_back() {
+                acc = func(acc, v);
+            }
+            if let Some((v, _)) = self.next_partial_back(default) {
+                acc = func(acc, v);
+            }
+            debug_assert!(self.next_partial_back(default).is_none());
+            acc
+        } else if let Some((v, _)) = self.next_partial_back(default) {
+            acc = func(start, v);
+            while let Some((v, _)) = self.next_partial_back(default) {
+                acc = func(acc, v);
+            }
+            debug_assert!(self.next_partial_back(default).is_none());
+            acc
+        } else {
+            start
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct PackedIter<'a, T : 'a + Packable> {
     pub position: usize,
+    pub end: usize,
     pub data: &'a [T],
 }
 
@@ -138,12 +309,18 @@ impl<'a, T> Iterator for PackedIter<'a, T> where T : Packable {
 
     #[inline(always)]
     fn next(&mut self) -> Option<Self::Item> {
-        self.data.get(self.position).map(|v| { self.position += 1; *v })
+        if self.position < self.end {
+            let ret = self.data[self.position];
+            self.position += 1;
+            Some(ret)
+        } else {
+            None
+        }
     }
 
     #[inline(always)]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.data.len() - self.position;
+        let remaining = self.end - self.position;
         (remaining, Some(remaining))
     }
 }
@@ -171,9 +348,14 @@ impl<'a, T> PackedIterator for PackedIter<'a, T> where T : Packable {
         self.position
     }
 
+    #[inline(always)]
+    fn scalar_remaining(&self) -> usize {
+        self.end - self.position
+    }
+
     #[inline(always)]
     fn next_vector(&mut self) -> Option<Self::Vector> {
-        if self.position + self.width() <= self.scalar_len() {
+        if self.position + self.width() <= self.end {
             let ret = Some(Self::Vector::load(self.data, self.position));
             self.position += Self::Vector::WIDTH;
             ret
@@ -183,15 +365,42 @@ impl<'a, T> PackedIterator for PackedIter<'a, T> where T : Packable {
     }
 
     #[inline(always)]
-    fn next_partial(&mut self, default: Self::Vector) -> Option<Self::Vector> where T : Packable {
-        if self.position < self.scalar_len() {
-            let mut ret = Self::Vector::splat(default.extract(0));
-            for i in 0..self.scalar_len() - self.position {
+    fn next_partial(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> where T : Packable {
+        if self.position < self.end {
+            let mut ret = default;
+            let count = self.end - self.position;
+            for i in 0..count {
                 ret = ret.replace(i, self.data[self.position + i].clone());
             }
 
-            self.position = self.scalar_len();
-            Some(ret)
+            self.position = self.end;
+            Some((ret, count))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn next_vector_back(&mut self) -> Option<Self::Vector> {
+        if self.position + self.width() <= self.end {
+            self.end -= Self::Vector::WIDTH;
+            Some(Self::Vector::load(self.data, self.end))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn next_partial_back(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> where T : Packable {
+        if self.position < self.end {
+            let mut ret = default;
+            let count = self.end - self.position;
+            for i in 0..count {
+                ret = ret.replace(i, self.data[self.position + i].clone());
+            }
+
+            self.end = self.position;
+            Some((ret, count))
         } else {
             None
         }
@@ -253,6 +462,162 @@ impl<'a, I: 'a + ?Sized> IntoPackedRefMutIterator<'a> for I
     }
 }
 
+/// An iterator which packs values read `stride` elements apart out of a
+/// strided, struct-of-arrays style slice, starting `offset` elements in.
+/// Useful for interleaved data such as RGBA pixels or XYZ vertices, where
+/// deinterleaving into a contiguous buffer before running SIMD code would
+/// otherwise be necessary.
+#[derive(Debug)]
+pub struct PackedStripe<'a, T : 'a + Packable> {
+    pub front: usize,
+    pub back: usize,
+    pub offset: usize,
+    pub stride: usize,
+    pub data: &'a [T],
+}
+
+impl<'a, T> PackedStripe<'a, T> where T : Packable {
+    #[inline(always)]
+    fn stripe_count(&self) -> usize {
+        if self.offset < self.data.len() {
+            (self.data.len() - self.offset + self.stride - 1) / self.stride
+        } else {
+            0
+        }
+    }
+}
+
+pub trait IntoPackedStripeIterator<'a, T : 'a + Packable> {
+    /// Return an iterator over this data which reads every `stride`th
+    /// element starting `offset` elements in, packing the result into
+    /// SIMD vectors. See `PackedIterator::simd_map` and
+    /// `PackedIterator::simd_reduce` for more information.
+    fn stride_simd_iter(&'a self, offset: usize, stride: usize) -> PackedStripe<'a, T>;
+}
+
+impl<'a, T : 'a + Packable> IntoPackedStripeIterator<'a, T> for [T] {
+    #[inline(always)]
+    fn stride_simd_iter(&'a self, offset: usize, stride: usize) -> PackedStripe<'a, T> {
+        let mut iter = PackedStripe {
+            front: 0,
+            back: 0,
+            offset: offset,
+            stride: stride,
+            data: self
+        };
+        iter.back = iter.stripe_count();
+        iter
+    }
+}
+
+impl<'a, T> Iterator for PackedStripe<'a, T> where T : Packable {
+    type Item = <PackedStripe<'a, T> as PackedIterator>::Scalar;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let ret = self.data[self.offset + self.front * self.stride];
+            self.front += 1;
+            Some(ret)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for PackedStripe<'a, T>
+    where T : Packable {
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.stripe_count()
+    }
+}
+
+impl<'a, T> PackedIterator for PackedStripe<'a, T> where T : Packable {
+    type Vector = <T as Packable>::Vector;
+    type Scalar = T;
+
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.stripe_count()
+    }
+
+    #[inline(always)]
+    fn scalar_position(&self) -> usize {
+        self.front
+    }
+
+    #[inline(always)]
+    fn scalar_remaining(&self) -> usize {
+        self.back - self.front
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Option<Self::Vector> {
+        if self.front + self.width() <= self.back {
+            let mut ret = Self::Vector::splat(self.data[self.offset + self.front * self.stride].clone());
+            for i in 0..self.width() {
+                ret = ret.replace(i, self.data[self.offset + (self.front + i) * self.stride].clone());
+            }
+            self.front += Self::Vector::WIDTH;
+            Some(ret)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn next_partial(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> {
+        if self.front < self.back {
+            let mut ret = default;
+            let count = self.back - self.front;
+            for i in 0..count {
+                ret = ret.replace(i, self.data[self.offset + (self.front + i) * self.stride].clone());
+            }
+            self.front = self.back;
+            Some((ret, count))
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn next_vector_back(&mut self) -> Option<Self::Vector> {
+        if self.front + self.width() <= self.back {
+            self.back -= Self::Vector::WIDTH;
+            let mut ret = Self::Vector::splat(self.data[self.offset + self.back * self.stride].clone());
+            for i in 0..self.width() {
+                ret = ret.replace(i, self.data[self.offset + (self.back + i) * self.stride].clone());
+            }
+            Some(ret)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn next_partial_back(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> {
+        if self.front < self.back {
+            let mut ret = default;
+            let count = self.back - self.front;
+            for i in 0..count {
+                ret = ret.replace(i, self.data[self.offset + (self.front + i) * self.stride].clone());
+            }
+            self.back = self.front;
+            Some((ret, count))
+        } else {
+            None
+        }
+    }
+}
+
 impl<A, B, I, F> Iterator for PackedMap<I, F>
     where I : PackedIterator<Scalar = <I as Iterator>::Item>, <I as Iterator>::Item : Packable, F : FnMut(I::Vector) -> A, A : Packed<Scalar = B>, B : Packable {
     type Item = B;
@@ -293,15 +658,413 @@ impl<'a, A, B, I, F> PackedIterator for PackedMap<I, F>
         self.iter.scalar_position()
     }
 
+    #[inline(always)]
+    fn scalar_remaining(&self) -> usize {
+        self.iter.scalar_remaining()
+    }
+
     #[inline(always)]
     fn next_vector(&mut self) -> Option<Self::Vector> {
         self.iter.next_vector().map(&mut self.func)
     }
 
     #[inline(always)]
-    fn next_partial(&mut self, default: Self::Vector) -> Option<Self::Vector> {
-        // TODO: Take a user-defined default and return number of elements actually mapped
-        self.iter.next_partial(A::default()).map(&mut self.func)
+    fn next_partial(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> {
+        self.iter.next_partial(I::Vector::default()).map(|(v, count)| {
+            let mut mapped = (&mut self.func)(v);
+            for i in count..Self::Vector::WIDTH {
+                mapped = mapped.replace(i, default.extract(i));
+            }
+            (mapped, count)
+        })
+    }
+
+    #[inline(always)]
+    fn next_vector_back(&mut self) -> Option<Self::Vector> {
+        self.iter.next_vector_back().map(&mut self.func)
+    }
+
+    #[inline(always)]
+    fn next_partial_back(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> {
+        self.iter.next_partial_back(I::Vector::default()).map(|(v, count)| {
+            let mut mapped = (&mut self.func)(v);
+            for i in count..Self::Vector::WIDTH {
+                mapped = mapped.replace(i, default.extract(i));
+            }
+            (mapped, count)
+        })
+    }
+}
+
+/// An iterator which pairs the vectors of two `PackedIterator`s, like
+/// `core::iter::Zip`.
+#[derive(Debug)]
+pub struct PackedZip<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A, B> PackedZip<A, B>
+    where A : PackedIterator, B : PackedIterator {
+
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        cmp::min(self.a.scalar_len(), self.b.scalar_len())
+    }
+
+    #[inline(always)]
+    fn scalar_position(&self) -> usize {
+        cmp::min(self.a.scalar_position(), self.b.scalar_position())
+    }
+
+    #[inline(always)]
+    fn scalar_remaining(&self) -> usize {
+        cmp::min(self.a.scalar_remaining(), self.b.scalar_remaining())
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Option<(A::Vector, B::Vector)> {
+        // Neither side may be polled unless both have a full vector ready;
+        // calling `next_vector` on the longer side first would silently
+        // pull and discard a vector the moment the shorter side runs dry,
+        // desynchronizing the two cursors. `scalar_remaining` (rather than
+        // `scalar_position`/`scalar_len`) is what actually tracks this,
+        // since it accounts for elements already taken off the back.
+        let width = self.a.width();
+        if self.a.scalar_remaining() >= width && self.b.scalar_remaining() >= width {
+            match (self.a.next_vector(), self.b.next_vector()) {
+                (Some(a), Some(b)) => Some((a, b)),
+                _ => None
+            }
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn next_partial(&mut self, default: (A::Vector, B::Vector)) -> Option<((A::Vector, B::Vector), usize)> {
+        // Delegating straight to `self.a.next_partial`/`self.b.next_partial`
+        // would drain whichever side is longer all the way to its own end,
+        // pairing the wrong tail elements together. Pull exactly as many
+        // scalars as the shorter side has left, so the excess tail of the
+        // longer side is left untouched for a later, independent consumer.
+        let remaining = cmp::min(self.a.scalar_remaining(), self.b.scalar_remaining());
+        if remaining == 0 {
+            return None;
+        }
+        let (mut a, mut b) = default;
+        for i in 0..remaining {
+            a = a.replace(i, self.a.next().unwrap());
+            b = b.replace(i, self.b.next().unwrap());
+        }
+        Some(((a, b), remaining))
+    }
+
+    #[inline(always)]
+    fn next_vector_back(&mut self) -> Option<(A::Vector, B::Vector)> {
+        // Mirrors `next_vector`: don't advance either back cursor unless
+        // both sides can still supply a full vector, or a short side would
+        // silently desync the longer one exactly as in the forward case.
+        let width = self.a.width();
+        if self.a.scalar_remaining() >= width && self.b.scalar_remaining() >= width {
+            match (self.a.next_vector_back(), self.b.next_vector_back()) {
+                (Some(a), Some(b)) => Some((a, b)),
+                _ => None
+            }
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn next_partial_back(&mut self, default: (A::Vector, B::Vector)) -> Option<((A::Vector, B::Vector), usize)> {
+        // Mirrors `next_partial`. By the time only a middle remainder is
+        // left on both sides there's no meaningful "front" or "back" to it
+        // (front and back cursors have met), so it's pulled with the plain
+        // forward scalar iterator exactly like the partial tail above,
+        // rather than delegating to each side's own next_partial_back and
+        // draining it all the way to that side's own end.
+        let remaining = cmp::min(self.a.scalar_remaining(), self.b.scalar_remaining());
+        if remaining == 0 {
+            return None;
+        }
+        let (mut a, mut b) = default;
+        for i in 0..remaining {
+            a = a.replace(i, self.a.next().unwrap());
+            b = b.replace(i, self.b.next().unwrap());
+        }
+        Some(((a, b), remaining))
+    }
+
+    #[inline(always)]
+    /// Return an iterator which calls `func` on the paired vectors of both
+    /// sources of this zip.
+    pub fn simd_map<A2, B2, F>(self, func: F) -> PackedZipMap<A, B, F>
+        where F : FnMut((A::Vector, B::Vector)) -> A2, A2 : Packed<Scalar = B2>, B2 : Packable {
+        PackedZipMap {
+            iter: self,
+            func: func
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PackedZipMap<A, B, F> {
+    pub iter: PackedZip<A, B>,
+    pub func: F,
+}
+
+impl<A, B, F, C, D> Iterator for PackedZipMap<A, B, F>
+    where A : PackedIterator, B : PackedIterator,
+          F : FnMut((A::Vector, B::Vector)) -> C, C : Packed<Scalar = D>, D : Packable {
+    type Item = D;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Check both sides still have something left before consuming from
+        // either, so a shorter `b` can't cause `a`'s scalar to be silently
+        // pulled and discarded (which would desync any later vector-level
+        // calls). `len()` reports each source's original size, not what's
+        // left, so the remaining count has to come from scalar_remaining
+        // instead, which also accounts for elements taken off the back.
+        if self.iter.a.scalar_remaining() == 0 ||
+            self.iter.b.scalar_remaining() == 0 {
+            return None;
+        }
+        let a = self.iter.a.next()?;
+        let b = self.iter.b.next()?;
+        Some((&mut self.func)((A::Vector::splat(a), B::Vector::splat(b))).coalesce())
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len() - self.iter.scalar_position() * self.width()) / self.width();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<A, B, F> ExactSizeIterator for PackedZipMap<A, B, F>
+    where Self : PackedIterator, A : PackedIterator, B : PackedIterator {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        cmp::min(self.iter.a.len(), self.iter.b.len())
+    }
+}
+
+impl<A, B, F, C, D> PackedIterator for PackedZipMap<A, B, F>
+    where A : PackedIterator, B : PackedIterator,
+          F : FnMut((A::Vector, B::Vector)) -> C, C : Packed<Scalar = D>, D : Packable {
+    type Vector = C;
+    type Scalar = D;
+
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.iter.scalar_len()
+    }
+
+    #[inline(always)]
+    fn scalar_position(&self) -> usize {
+        self.iter.scalar_position()
+    }
+
+    #[inline(always)]
+    fn scalar_remaining(&self) -> usize {
+        self.iter.scalar_remaining()
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Option<Self::Vector> {
+        self.iter.next_vector().map(&mut self.func)
+    }
+
+    #[inline(always)]
+    fn next_partial(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> {
+        self.iter.next_partial((A::Vector::default(), B::Vector::default())).map(|(v, count)| {
+            let mut mapped = (&mut self.func)(v);
+            for i in count..Self::Vector::WIDTH {
+                mapped = mapped.replace(i, default.extract(i));
+            }
+            (mapped, count)
+        })
+    }
+
+    #[inline(always)]
+    fn next_vector_back(&mut self) -> Option<Self::Vector> {
+        self.iter.next_vector_back().map(&mut self.func)
+    }
+
+    #[inline(always)]
+    fn next_partial_back(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> {
+        self.iter.next_partial_back((A::Vector::default(), B::Vector::default())).map(|(v, count)| {
+            let mut mapped = (&mut self.func)(v);
+            for i in count..Self::Vector::WIDTH {
+                mapped = mapped.replace(i, default.extract(i));
+            }
+            (mapped, count)
+        })
+    }
+}
+
+/// An iterator which pairs each vector of this iterator with an index
+/// vector reporting the absolute scalar position of each of its lanes,
+/// like `core::iter::Enumerate`.
+#[derive(Debug)]
+pub struct PackedEnumerate<I, V> {
+    pub iter: I,
+    back_consumed: usize,
+    _marker: PhantomData<V>,
+}
+
+impl<I, V> PackedEnumerate<I, V>
+    where I : PackedIterator, V : Packed<Scalar = u32> {
+
+    #[inline(always)]
+    fn index_vector(position: usize) -> V {
+        let mut ret = V::splat(position as u32);
+        for i in 0..V::WIDTH {
+            ret = ret.replace(i, (position + i) as u32);
+        }
+        ret
+    }
+
+    #[inline(always)]
+    fn partial_index_vector(position: usize, count: usize, default: V) -> V {
+        let mut ret = default;
+        for i in 0..count {
+            ret = ret.replace(i, (position + i) as u32);
+        }
+        ret
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Option<(V, I::Vector)> {
+        let position = self.iter.scalar_position();
+        self.iter.next_vector().map(|v| (Self::index_vector(position), v))
+    }
+
+    #[inline(always)]
+    fn next_partial(&mut self, default: (V, I::Vector)) -> Option<((V, I::Vector), usize)> {
+        let position = self.iter.scalar_position();
+        self.iter.next_partial(default.1).map(|(v, count)| ((Self::partial_index_vector(position, count, default.0), v), count))
+    }
+
+    #[inline(always)]
+    fn next_vector_back(&mut self) -> Option<(V, I::Vector)> {
+        let width = self.iter.width();
+        let back_consumed = &mut self.back_consumed;
+        let scalar_len = self.iter.scalar_len();
+        self.iter.next_vector_back().map(|v| {
+            *back_consumed += width;
+            (Self::index_vector(scalar_len - *back_consumed), v)
+        })
+    }
+
+    #[inline(always)]
+    fn next_partial_back(&mut self, default: (V, I::Vector)) -> Option<((V, I::Vector), usize)> {
+        let position = self.iter.scalar_position();
+        self.iter.next_partial_back(default.1).map(|(v, count)| ((Self::partial_index_vector(position, count, default.0), v), count))
+    }
+
+    #[inline(always)]
+    /// Return an iterator which calls `func` on each `(index, data)` pair
+    /// of vectors.
+    pub fn simd_map<A, B, F>(self, func: F) -> PackedEnumerateMap<I, V, F>
+        where F : FnMut((V, I::Vector)) -> A, A : Packed<Scalar = B>, B : Packable {
+        PackedEnumerateMap {
+            iter: self,
+            func: func
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PackedEnumerateMap<I, V, F> {
+    pub iter: PackedEnumerate<I, V>,
+    pub func: F,
+}
+
+impl<I, V, F, A, B> Iterator for PackedEnumerateMap<I, V, F>
+    where I : PackedIterator, V : Packed<Scalar = u32>,
+          F : FnMut((V, I::Vector)) -> A, A : Packed<Scalar = B>, B : Packable {
+    type Item = B;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.iter.iter.scalar_position();
+        let v = self.iter.iter.next()?;
+        // Every lane of this scalar's index vector carries the same real
+        // index, mirroring how every other adaptor's scalar fallback
+        // splats its one live value instead of spreading it across lanes.
+        let idx = V::splat(position as u32);
+        Some((&mut self.func)((idx, I::Vector::splat(v))).coalesce())
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len() - self.iter.iter.scalar_position() * self.width()) / self.width();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<I, V, F> ExactSizeIterator for PackedEnumerateMap<I, V, F>
+    where Self : PackedIterator, I : PackedIterator {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.iter.iter.len()
+    }
+}
+
+impl<I, V, F, A, B> PackedIterator for PackedEnumerateMap<I, V, F>
+    where I : PackedIterator, V : Packed<Scalar = u32>,
+          F : FnMut((V, I::Vector)) -> A, A : Packed<Scalar = B>, B : Packable {
+    type Vector = A;
+    type Scalar = B;
+
+    #[inline(always)]
+    fn scalar_len(&self) -> usize {
+        self.iter.iter.scalar_len()
+    }
+
+    #[inline(always)]
+    fn scalar_position(&self) -> usize {
+        self.iter.iter.scalar_position()
+    }
+
+    #[inline(always)]
+    fn scalar_remaining(&self) -> usize {
+        self.iter.iter.scalar_remaining()
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Option<Self::Vector> {
+        self.iter.next_vector().map(&mut self.func)
+    }
+
+    #[inline(always)]
+    fn next_partial(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> {
+        self.iter.next_partial((V::default(), I::Vector::default())).map(|(v, count)| {
+            let mut mapped = (&mut self.func)(v);
+            for i in count..Self::Vector::WIDTH {
+                mapped = mapped.replace(i, default.extract(i));
+            }
+            (mapped, count)
+        })
+    }
+
+    #[inline(always)]
+    fn next_vector_back(&mut self) -> Option<Self::Vector> {
+        self.iter.next_vector_back().map(&mut self.func)
+    }
+
+    #[inline(always)]
+    fn next_partial_back(&mut self, default: Self::Vector) -> Option<(Self::Vector, usize)> {
+        self.iter.next_partial_back((V::default(), I::Vector::default())).map(|(v, count)| {
+            let mut mapped = (&mut self.func)(v);
+            for i in count..Self::Vector::WIDTH {
+                mapped = mapped.replace(i, default.extract(i));
+            }
+            (mapped, count)
+        })
     }
 }
 
@@ -335,9 +1098,10 @@ impl<'a, T, I> IntoScalar<T> for I
                 vec.store(ret.as_mut_slice(), offset);
                 offset += Self::Vector::WIDTH;
             }
-            while let Some(scl) = self.next() {
-                ret[offset] = scl;
-                offset += 1;
+            if let Some((vec, count)) = self.next_partial(Self::Vector::default()) {
+                for i in 0..count {
+                    ret[offset + i] = vec.extract(i);
+                }
             }
         }
         ret
@@ -352,10 +1116,174 @@ impl<'a, T, I> IntoScalar<T> for I
             offset += Self::Vector::WIDTH;
         }
 
-        while let Some(scl) = self.next() {
-            fill[offset] = scl;
-            offset += 1;
+        if let Some((vec, count)) = self.next_partial(Self::Vector::default()) {
+            for i in 0..count {
+                fill[offset + i] = vec.extract(i);
+            }
         }
         fill
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vecs::{f32s, u8s, u32s};
+
+    #[test]
+    fn zip_pairs_lanes_of_equal_length_sources() {
+        let a = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+        let b = [10.0f32, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0];
+        let sums = a.simd_iter().zip(b.simd_iter()).simd_map(|(x, y)| x + y).scalar_collect();
+        assert_eq!(sums, vec![11.0, 22.0, 33.0, 44.0, 55.0, 66.0, 77.0, 88.0]);
+    }
+
+    #[test]
+    fn zip_of_mismatched_lengths_stops_at_the_shorter_source() {
+        let a = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let b = [10.0f32, 20.0, 30.0, 40.0, 50.0, 60.0];
+
+        let zip = a.simd_iter().zip(b.simd_iter());
+        assert_eq!(zip.scalar_len(), b.len());
+
+        let sums = zip.simd_map(|(x, y)| x + y).scalar_collect();
+        assert_eq!(sums, vec![11.0, 22.0, 33.0, 44.0, 55.0, 66.0]);
+    }
+
+    #[test]
+    fn zip_map_scalar_iteration_stops_when_either_side_is_exhausted() {
+        let a = [1.0f32, 2.0, 3.0];
+        let b = [10.0f32, 20.0];
+
+        let mut zip_map = a.simd_iter().zip(b.simd_iter()).simd_map(|(x, y)| x + y);
+        assert_eq!(zip_map.next(), Some(11.0));
+        assert_eq!(zip_map.next(), Some(22.0));
+        assert_eq!(zip_map.next(), None);
+    }
+
+    #[test]
+    fn stride_simd_iter_reads_every_nth_element_from_an_offset() {
+        // Interleaved RGBA-style buffer; pick out the "G" channel.
+        let pixels = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let green: Vec<u8> = pixels.stride_simd_iter(1, 4).simd_map(|v| v).scalar_collect();
+        assert_eq!(green, vec![2, 6, 10]);
+    }
+
+    #[test]
+    fn stride_simd_iter_scalar_fill_writes_back_transformed_values() {
+        let pixels = [1u8, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let mut out = [0u8; 3];
+        pixels.stride_simd_iter(1, 4).simd_map(|v| v + u8s::splat(100)).scalar_fill(&mut out);
+        assert_eq!(out, [102, 106, 110]);
+    }
+
+    #[test]
+    fn simd_reduce_tree_sums_all_elements_across_many_carry_levels() {
+        // 37 elements forces several vectors through the carry stack (and
+        // a non-empty partial tail), exercising more than one fold level.
+        let data: Vec<f32> = (1..=37).map(|x| x as f32).collect();
+        let total: f32 = data.simd_iter().simd_reduce_tree(f32s::splat(0.0), |acc, v| acc + v).sum();
+        let expected: f32 = data.iter().sum();
+        assert_eq!(total, expected);
+    }
+
+    #[test]
+    fn simd_reduce_tree_handles_less_than_one_vector() {
+        let data = [1.0f32, 2.0, 3.0];
+        let total: f32 = data.simd_iter().simd_reduce_tree(f32s::splat(0.0), |acc, v| acc + v).sum();
+        assert_eq!(total, 6.0);
+    }
+
+    #[test]
+    fn simd_enumerate_indices_match_scalar_position_on_a_non_multiple_of_width() {
+        // 7 elements so the enumeration must cross a partial tail on most
+        // vector widths, without leaking out-of-range garbage indices.
+        let data = [10.0f32, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0];
+        let indices: Vec<u32> = data.simd_iter().simd_enumerate::<u32s>()
+            .simd_map(|(idx, _)| idx).scalar_collect();
+        assert_eq!(indices, vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn mapped_partial_honors_a_non_zero_caller_supplied_default() {
+        // 5 isn't a multiple of most vector widths, so this exercises a
+        // mapped partial tail. A product reduction's identity is 1.0, not
+        // f32's Default (0.0); if the mapped partial ever fell back to
+        // A::default() instead of the caller's default, the padding lanes
+        // would zero out the whole product instead of leaving it untouched.
+        let data = [2.0f32, 3.0, 4.0, 5.0, 6.0];
+        let product = data.simd_iter()
+            .simd_map(|v| v * f32s::splat(2.0))
+            .simd_reduce(1.0, f32s::splat(1.0), |acc, v| acc * v.product());
+        let expected: f32 = data.iter().map(|x| x * 2.0).product();
+        assert_eq!(product, expected);
+    }
+
+    #[test]
+    fn mapped_partial_reports_the_same_valid_lane_count_as_its_source() {
+        // Fewer elements than any realistic vector width, so the whole
+        // thing is a single partial with no preceding full vectors.
+        let data = [1.0f32, 2.0, 3.0];
+        let mut mapped = data.simd_iter().simd_map(|v| v * f32s::splat(10.0));
+        let (_, count) = mapped.next_partial(f32s::splat(0.0)).unwrap();
+        assert_eq!(count, data.len());
+    }
+
+    #[test]
+    fn simd_enumerate_index_vector_lanes_line_up_with_the_data_vector() {
+        // Enough elements to guarantee at least one full vector regardless
+        // of the target's lane width.
+        let data: Vec<f32> = (0..64).map(|i| (i * 10) as f32).collect();
+        let (idx, v) = data.simd_iter().simd_enumerate::<u32s>().next_vector().unwrap();
+        for i in 0..u32s::WIDTH {
+            assert_eq!(v.extract(i), data[idx.extract(i) as usize]);
+        }
+    }
+
+    #[test]
+    fn packed_iter_back_cursor_meets_front_cursor_mid_vector() {
+        // One vector off the front, one off the back, then a 2-element
+        // remainder where the cursors meet, regardless of the target's
+        // actual lane width.
+        let width = f32s::WIDTH;
+        let data: Vec<f32> = (0..(2 * width + 2)).map(|i| i as f32).collect();
+        let mut iter = data.simd_iter();
+
+        assert!(iter.next_vector().is_some());
+        assert!(iter.next_vector_back().is_some());
+
+        let (_, count) = iter.next_partial_back(f32s::splat(0.0)).unwrap();
+        assert_eq!(count, 2);
+        assert!(iter.next_partial_back(f32s::splat(0.0)).is_none());
+    }
+
+    #[test]
+    fn zip_back_cursor_stops_at_the_shorter_source_without_desyncing() {
+        // `a` is longer than `b` by more than one vector's worth of
+        // elements, so draining the zip from the back must stop pulling
+        // from `a` the moment `b` can no longer keep up, no matter which
+        // lane width the target uses.
+        let width = f32s::WIDTH;
+        let a: Vec<f32> = (0..(3 * width + 2)).map(|i| i as f32 + 1.0).collect();
+        let b: Vec<f32> = (0..(width + 2)).map(|i| (i as f32 + 1.0) * 10.0).collect();
+        let zip_len = cmp::min(a.len(), b.len());
+
+        let mut zip = a.simd_iter().zip(b.simd_iter());
+        let mut consumed = 0;
+        while zip.next_vector_back().is_some() {
+            consumed += width;
+        }
+        if let Some((_, count)) = zip.next_partial_back((f32s::default(), f32s::default())) {
+            consumed += count;
+        }
+
+        // Every element the shorter source owns was folded in exactly
+        // once, and nothing beyond that: the desync this guards against
+        // would show up here as either a short count (a panic further up,
+        // in `next()`, once the cursors fall out of step) or a count past
+        // `zip_len` (the longer side's cursor silently racing ahead).
+        assert_eq!(consumed, zip_len);
+        assert!(zip.next_vector_back().is_none());
+        assert!(zip.next_partial_back((f32s::default(), f32s::default())).is_none());
+    }
+}